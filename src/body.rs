@@ -0,0 +1,237 @@
+use actix_web::{
+    Error as ActixError, FromRequest, HttpMessage, HttpRequest, HttpResponse, ResponseError,
+    dev::Payload,
+    error::PayloadError,
+    http::{StatusCode, header::ContentType},
+};
+use futures_util::future::LocalBoxFuture;
+use serde::{Serialize, de::DeserializeOwned};
+use std::{fmt, marker::PhantomData, sync::Arc};
+
+use crate::format::Format;
+use crate::{BodyReadError, DEFAULT_LIMIT, read_body_with_limit};
+
+/// Closure invoked to build the `actix_web::Error` returned to the client
+/// whenever a [`Body<T, F>`] extraction fails, overriding the default messages.
+type BodyErrorHandler = Arc<dyn Fn(BodyError, &HttpRequest) -> ActixError>;
+
+/// Errors produced while extracting a [`Body<T, F>`].
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum BodyError {
+    /// The body exceeded the allowed limit.
+    Overflow { limit: usize, length: Option<usize> },
+    /// The request's `Content-Type` didn't match the selected [`Format`].
+    ContentType { expected: &'static str },
+    /// The request body could not be read from the client.
+    Payload(PayloadError),
+    /// The body was within limits and the right content type, but failed to deserialize.
+    Deserialize { path: String, message: String },
+}
+
+impl fmt::Display for BodyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BodyError::Overflow {
+                limit,
+                length: Some(length),
+            } => write!(
+                f,
+                "payload ({} bytes) is larger than allowed (limit: {} bytes)",
+                length, limit
+            ),
+            BodyError::Overflow { limit, length: None } => write!(
+                f,
+                "payload has exceeded the allowed limit ({} bytes) while reading the body",
+                limit
+            ),
+            BodyError::ContentType { expected } => write!(f, "Content-Type must be `{}`", expected),
+            BodyError::Payload(e) => write!(f, "Failed to read request body: {}", e),
+            BodyError::Deserialize { path, message } => {
+                write!(f, "Invalid value at {}: {}", path, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BodyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BodyError::Payload(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DeserializeErrorBody<'a> {
+    error: &'static str,
+    path: &'a str,
+    message: &'a str,
+}
+
+impl ResponseError for BodyError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            BodyError::Overflow { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            BodyError::ContentType { .. } => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            BodyError::Payload(_) => StatusCode::BAD_REQUEST,
+            BodyError::Deserialize { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            BodyError::Deserialize { path, message } => {
+                let body = DeserializeErrorBody {
+                    error: "invalid_body",
+                    path,
+                    message,
+                };
+                HttpResponse::build(self.status_code())
+                    .content_type(ContentType::json())
+                    .json(&body)
+            }
+            _ => HttpResponse::build(self.status_code()).body(self.to_string()),
+        }
+    }
+}
+
+/// App-data configuration for the [`Body<T, F>`] extractor, analogous to
+/// [`crate::JsonConfig`].
+///
+/// The accepted `Content-Type` is always the format's own
+/// [`Format::CONTENT_TYPE`] — only the size limit and error response are
+/// configurable:
+///
+/// ```ignore
+/// App::new().app_data(BodyConfig::default().limit(4096))
+/// ```
+#[derive(Clone)]
+pub struct BodyConfig {
+    limit: usize,
+    error_handler: Option<BodyErrorHandler>,
+}
+
+impl Default for BodyConfig {
+    fn default() -> Self {
+        BodyConfig {
+            limit: DEFAULT_LIMIT,
+            error_handler: None,
+        }
+    }
+}
+
+impl BodyConfig {
+    /// Sets the maximum number of bytes accepted in the request body.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets a closure invoked to build the `actix_web::Error` returned to the
+    /// client whenever extraction fails, overriding the default messages.
+    pub fn error_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(BodyError, &HttpRequest) -> ActixError + 'static,
+    {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+
+    fn error_response(&self, err: BodyError, req: &HttpRequest) -> ActixError {
+        match &self.error_handler {
+            Some(handler) => handler(err, req),
+            None => err.into(),
+        }
+    }
+}
+
+/// A path-aware body extractor generic over its wire [`Format`].
+///
+/// This generalizes [`crate::Json<T>`] to any format selected via
+/// `Content-Type`. Errors from the JSON and MessagePack formats carry the
+/// `serde_path_to_error` path of the failing value; CBOR errors carry only a
+/// message:
+///
+/// ```ignore
+/// async fn handler(body: Body<MyPayload, MsgPackFormat>) -> impl Responder { ... }
+/// ```
+pub struct Body<T, F>(pub T, PhantomData<F>);
+
+impl<T, F> Body<T, F> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, F> std::ops::Deref for Body<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, F> std::ops::DerefMut for Body<T, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, F> FromRequest for Body<T, F>
+where
+    T: DeserializeOwned + 'static,
+    F: Format + 'static,
+{
+    type Error = ActixError;
+    type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let req = req.clone();
+        let payload = payload.take();
+        let config = req
+            .app_data::<BodyConfig>()
+            .cloned()
+            .unwrap_or_default();
+
+        Box::pin(async move {
+            let accepted = req
+                .content_type()
+                .split(';')
+                .next()
+                .is_some_and(|ct| ct.trim().eq_ignore_ascii_case(F::CONTENT_TYPE));
+            if !accepted {
+                return Err(config.error_response(
+                    BodyError::ContentType {
+                        expected: F::CONTENT_TYPE,
+                    },
+                    &req,
+                ));
+            }
+
+            let body = read_body_with_limit(&req, payload, config.limit)
+                .await
+                .map_err(|e| match e {
+                    BodyReadError::Overflow { limit, length } => {
+                        config.error_response(BodyError::Overflow { limit, length }, &req)
+                    }
+                    BodyReadError::Payload(e) => {
+                        config.error_response(BodyError::Payload(e), &req)
+                    }
+                })?;
+
+            let value = F::deserialize(&body).map_err(|e| {
+                config.error_response(
+                    BodyError::Deserialize {
+                        path: e.path,
+                        message: e.message,
+                    },
+                    &req,
+                )
+            })?;
+
+            Ok(Body(value, PhantomData))
+        })
+    }
+}
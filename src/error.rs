@@ -0,0 +1,103 @@
+use actix_web::{
+    ResponseError,
+    error::PayloadError,
+    http::{StatusCode, header::ContentType},
+};
+use serde::Serialize;
+use std::fmt;
+
+/// Errors produced while extracting a [`crate::Json<T>`].
+///
+/// Implements [`ResponseError`] so each variant maps to the appropriate status
+/// code out of the box. Pass a [`crate::JsonConfig::error_handler`] to
+/// customize the response instead.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum JsonError {
+    /// The body exceeded the configured `limit`.
+    Overflow { limit: usize, length: Option<usize> },
+    /// The request's `Content-Type` was not accepted by the configured predicate.
+    ContentType,
+    /// The request body could not be read from the client.
+    Payload(PayloadError),
+    /// The body was within limits and the right content type, but failed to deserialize.
+    Deserialize {
+        path: String,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::Overflow {
+                limit,
+                length: Some(length),
+            } => write!(
+                f,
+                "JSON payload ({} bytes) is larger than allowed (limit: {} bytes)",
+                length, limit
+            ),
+            JsonError::Overflow { limit, length: None } => write!(
+                f,
+                "JSON payload has exceeded the allowed limit ({} bytes) while reading the body",
+                limit
+            ),
+            JsonError::ContentType => write!(f, "Content-Type must be `application/json`"),
+            JsonError::Payload(e) => write!(f, "Failed to read request body: {}", e),
+            JsonError::Deserialize { path, source } => {
+                write!(f, "Invalid JSON at {}: {}", path, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for JsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            JsonError::Payload(e) => Some(e),
+            JsonError::Deserialize { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Machine-readable body returned for [`JsonError::Deserialize`], so clients
+/// can map `path` directly onto form fields without parsing error text.
+#[derive(Serialize)]
+struct DeserializeErrorBody<'a> {
+    error: &'static str,
+    path: &'a str,
+    message: String,
+    line: usize,
+    column: usize,
+}
+
+impl ResponseError for JsonError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            JsonError::Overflow { .. } => StatusCode::PAYLOAD_TOO_LARGE,
+            JsonError::ContentType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            JsonError::Payload(_) => StatusCode::BAD_REQUEST,
+            JsonError::Deserialize { .. } => StatusCode::BAD_REQUEST,
+        }
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        match self {
+            JsonError::Deserialize { path, source } => {
+                let body = DeserializeErrorBody {
+                    error: "invalid_json",
+                    path,
+                    message: source.to_string(),
+                    line: source.line(),
+                    column: source.column(),
+                };
+                actix_web::HttpResponse::build(self.status_code())
+                    .content_type(ContentType::json())
+                    .json(&body)
+            }
+            _ => actix_web::HttpResponse::build(self.status_code()).body(self.to_string()),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use serde::de::DeserializeOwned;
+
+/// A deserialization failure from any [`Format`]. `path` is the
+/// `serde_path_to_error` path of the failing value; it is empty for the CBOR
+/// backend, which is not wrapped with `serde_path_to_error`.
+#[derive(Debug)]
+pub struct FormatError {
+    pub path: String,
+    pub message: String,
+}
+
+/// A deserialization backend pluggable into the [`crate::Body<T, F>`] extractor.
+///
+/// Each backend answers to a `Content-Type`. The JSON and MessagePack backends
+/// wrap their `serde::Deserializer` with `serde_path_to_error`, so MessagePack
+/// gets the same "invalid value at `<path>`" diagnostics as JSON; the CBOR
+/// backend reports the error message without a path.
+pub trait Format {
+    /// The `Content-Type` a request must carry to be routed to this format.
+    const CONTENT_TYPE: &'static str;
+
+    /// Deserializes `T` from the buffered request body.
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError>;
+}
+
+/// The JSON wire format, backed by `serde_json`.
+pub struct JsonFormat;
+
+impl Format for JsonFormat {
+    const CONTENT_TYPE: &'static str = "application/json";
+
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+        let jd = &mut serde_json::Deserializer::from_slice(bytes);
+        serde_path_to_error::deserialize(jd).map_err(|e| FormatError {
+            path: e.path().to_string(),
+            message: e.inner().to_string(),
+        })
+    }
+}
+
+/// The MessagePack wire format, backed by `rmp-serde`. Requires the `msgpack` feature.
+#[cfg(feature = "msgpack")]
+pub struct MsgPackFormat;
+
+#[cfg(feature = "msgpack")]
+impl Format for MsgPackFormat {
+    const CONTENT_TYPE: &'static str = "application/msgpack";
+
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+        let mut de = rmp_serde::Deserializer::new(bytes);
+        serde_path_to_error::deserialize(&mut de).map_err(|e| FormatError {
+            path: e.path().to_string(),
+            message: e.inner().to_string(),
+        })
+    }
+}
+
+/// The CBOR wire format, backed by `ciborium`. Requires the `cbor` feature.
+///
+/// `ciborium` doesn't expose its `Deserializer` publicly, so it can't be
+/// wrapped with `serde_path_to_error`; errors carry a message but no `path`.
+#[cfg(feature = "cbor")]
+pub struct CborFormat;
+
+#[cfg(feature = "cbor")]
+impl Format for CborFormat {
+    const CONTENT_TYPE: &'static str = "application/cbor";
+
+    fn deserialize<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, FormatError> {
+        ciborium::from_reader(bytes).map_err(|e| FormatError {
+            path: String::new(),
+            message: e.to_string(),
+        })
+    }
+}
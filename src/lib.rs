@@ -1,13 +1,156 @@
 use actix_web::{
-    Error as ActixError, FromRequest, HttpRequest, dev::Payload, error::ErrorBadRequest, web::Bytes,
+    Error as ActixError, FromRequest, HttpMessage, HttpRequest, HttpResponse, Responder,
+    dev::Payload,
+    error::PayloadError,
+    http::header::ContentType,
+    web::BytesMut,
 };
-use futures_util::future::LocalBoxFuture;
-use serde::de::DeserializeOwned;
+use futures_util::{StreamExt, future::LocalBoxFuture};
+use serde::{Serialize, de::DeserializeOwned};
+use std::sync::Arc;
+
+mod body;
+mod error;
+mod format;
+pub use body::{Body, BodyConfig, BodyError};
+pub use error::JsonError;
+pub use format::{Format, FormatError, JsonFormat};
+#[cfg(feature = "cbor")]
+pub use format::CborFormat;
+#[cfg(feature = "msgpack")]
+pub use format::MsgPackFormat;
+
+/// Default payload size limit, in bytes, applied when a request doesn't carry
+/// its own [`JsonConfig`] or [`BodyConfig`] as app data. Mirrors
+/// `actix_web::web::JsonConfig`'s default.
+pub(crate) const DEFAULT_LIMIT: usize = 2 * 1_048_576; // 2 MiB
+
+/// Failure from [`read_body_with_limit`], shared by every extractor that reads
+/// a buffered body under a byte cap; each extractor maps this to its own error type.
+pub(crate) enum BodyReadError {
+    Overflow { limit: usize, length: Option<usize> },
+    Payload(PayloadError),
+}
+
+/// Reads `payload` into memory, honoring the `Content-Length` header up front
+/// and enforcing `limit` while accumulating chunks in case it's absent or lying.
+pub(crate) async fn read_body_with_limit(
+    req: &HttpRequest,
+    mut payload: Payload,
+    limit: usize,
+) -> Result<BytesMut, BodyReadError> {
+    let length = req
+        .headers()
+        .get(actix_web::http::header::CONTENT_LENGTH)
+        .and_then(|l| l.to_str().ok())
+        .and_then(|l| l.parse::<usize>().ok());
+
+    if let Some(length) = length {
+        if length > limit {
+            return Err(BodyReadError::Overflow {
+                limit,
+                length: Some(length),
+            });
+        }
+    }
+
+    let mut body = BytesMut::with_capacity(8192);
+    while let Some(chunk) = payload.next().await {
+        let chunk = chunk.map_err(BodyReadError::Payload)?;
+        if body.len() + chunk.len() > limit {
+            return Err(BodyReadError::Overflow { limit, length: None });
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(body)
+}
+
+/// Predicate used by [`JsonConfig::content_type`] to decide whether a
+/// request's `Content-Type` is acceptable.
+type ContentTypePredicate = Arc<dyn Fn(&HttpRequest) -> bool>;
+
+/// Closure invoked to build the `actix_web::Error` returned to the client
+/// whenever a [`Json<T>`] extraction fails, overriding the default messages.
+type JsonErrorHandler = Arc<dyn Fn(JsonError, &HttpRequest) -> ActixError>;
+
+/// App-data configuration for the [`Json<T>`] extractor, analogous to
+/// `actix_web::web::JsonConfig`.
+///
+/// Register it on the `App` (or a scope) to customize the behavior of every
+/// `Json<T>` extraction within that scope:
+///
+/// ```ignore
+/// App::new().app_data(JsonConfig::default().limit(4096))
+/// ```
+#[derive(Clone)]
+pub struct JsonConfig {
+    limit: usize,
+    content_type: Option<ContentTypePredicate>,
+    error_handler: Option<JsonErrorHandler>,
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        JsonConfig {
+            limit: DEFAULT_LIMIT,
+            content_type: None,
+            error_handler: None,
+        }
+    }
+}
+
+impl JsonConfig {
+    /// Sets the maximum number of bytes accepted in the request body.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets a predicate used to decide whether a request's `Content-Type` is
+    /// acceptable. When unset, only `application/json` is accepted.
+    pub fn content_type<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&HttpRequest) -> bool + 'static,
+    {
+        self.content_type = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Sets a closure invoked to build the `actix_web::Error` returned to the
+    /// client whenever extraction fails, overriding the default messages.
+    pub fn error_handler<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(JsonError, &HttpRequest) -> ActixError + 'static,
+    {
+        self.error_handler = Some(Arc::new(handler));
+        self
+    }
+
+    fn accepts_content_type(&self, req: &HttpRequest) -> bool {
+        match &self.content_type {
+            Some(predicate) => predicate(req),
+            None => req
+                .content_type()
+                .split(';')
+                .next()
+                .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json")),
+        }
+    }
+
+    fn error_response(&self, err: JsonError, req: &HttpRequest) -> ActixError {
+        match &self.error_handler {
+            Some(handler) => handler(err, req),
+            None => err.into(),
+        }
+    }
+}
 
 /// Custom JSON extractor that uses serde_path_to_error for detailed error messages.
 ///
 /// This is a drop-in replacement for `actix_web::web::Json<T>` that provides
-/// detailed JSON path information when deserialization fails.
+/// detailed JSON path information when deserialization fails. Its behavior can
+/// be customized per-scope by registering a [`JsonConfig`] as app data.
 pub struct Json<T>(pub T);
 
 impl<T> Json<T> {
@@ -35,16 +178,34 @@ impl<T: DeserializeOwned + 'static> FromRequest for Json<T> {
     type Future = LocalBoxFuture<'static, Result<Self, Self::Error>>;
 
     fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
-        let bytes_fut = Bytes::from_request(req, payload);
+        let req = req.clone();
+        let payload = payload.take();
+        let config = req
+            .app_data::<JsonConfig>()
+            .cloned()
+            .unwrap_or_default();
 
         Box::pin(async move {
-            let bytes = bytes_fut
+            if !config.accepts_content_type(&req) {
+                return Err(config.error_response(JsonError::ContentType, &req));
+            }
+
+            let body = read_body_with_limit(&req, payload, config.limit)
                 .await
-                .map_err(|e| ErrorBadRequest(format!("Failed to read request body: {}", e)))?;
+                .map_err(|e| match e {
+                    BodyReadError::Overflow { limit, length } => {
+                        config.error_response(JsonError::Overflow { limit, length }, &req)
+                    }
+                    BodyReadError::Payload(e) => {
+                        config.error_response(JsonError::Payload(e), &req)
+                    }
+                })?;
 
-            let jd = &mut serde_json::Deserializer::from_slice(&bytes);
+            let jd = &mut serde_json::Deserializer::from_slice(&body);
             let value = serde_path_to_error::deserialize(jd).map_err(|e| {
-                ErrorBadRequest(format!("Invalid JSON at {}: {}", e.path(), e.inner()))
+                let path = e.path().to_string();
+                let source = e.into_inner();
+                config.error_response(JsonError::Deserialize { path, source }, &req)
             })?;
 
             Ok(Json(value))
@@ -52,6 +213,20 @@ impl<T: DeserializeOwned + 'static> FromRequest for Json<T> {
     }
 }
 
+impl<T: Serialize> Responder for Json<T> {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _req: &HttpRequest) -> HttpResponse<Self::Body> {
+        match serde_json::to_string(&self.0) {
+            Ok(body) => HttpResponse::Ok()
+                .content_type(ContentType::json())
+                .body(body),
+            Err(e) => HttpResponse::InternalServerError()
+                .body(format!("Failed to serialize response as JSON: {}", e)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,5 +288,234 @@ mod tests {
 
         let resp = test::call_service(&app, req).await;
         assert!(resp.status().is_client_error());
+
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error"], "invalid_json");
+        assert_eq!(body["path"], "age");
+        assert!(body["message"].is_string());
+        assert!(body["line"].is_u64());
+        assert!(body["column"].is_u64());
+    }
+
+    #[actix_web::test]
+    async fn test_payload_too_large() {
+        async fn handler(_data: Json<TestData>) -> HttpResponse {
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(JsonConfig::default().limit(10))
+                .route("/test", web::post().to(handler)),
+        )
+        .await;
+
+        let payload = serde_json::json!({
+            "name": "Test",
+            "age": 20,
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .set_json(&payload)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[actix_web::test]
+    async fn test_content_type_rejected() {
+        async fn handler(_data: Json<TestData>) -> HttpResponse {
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(App::new().route("/test", web::post().to(handler))).await;
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .insert_header(actix_web::http::header::ContentType::plaintext())
+            .set_payload(r#"{"name":"Test","age":20}"#)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(
+            resp.status(),
+            actix_web::http::StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_custom_error_handler() {
+        async fn handler(_data: Json<TestData>) -> HttpResponse {
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(JsonConfig::default().error_handler(|_err, _req| {
+                    actix_web::error::ErrorImATeapot("custom handler")
+                }))
+                .route("/test", web::post().to(handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .set_json(serde_json::json!({ "name": "Test", "age": "invalid" }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::IM_A_TEAPOT);
+    }
+
+    #[actix_web::test]
+    async fn test_json_as_responder() {
+        async fn handler() -> Json<TestData> {
+            Json(TestData {
+                name: "Test".to_string(),
+                age: 20,
+            })
+        }
+
+        let app = test::init_service(App::new().route("/test", web::get().to(handler))).await;
+
+        let req = test::TestRequest::get().uri("/test").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert!(resp.status().is_success());
+        assert_eq!(
+            resp.headers().get(actix_web::http::header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+
+        let body: TestData = test::read_body_json(resp).await;
+        assert_eq!(
+            body,
+            TestData {
+                name: "Test".to_string(),
+                age: 20,
+            }
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_body_with_json_format() {
+        async fn handler(data: Body<TestData, JsonFormat>) -> HttpResponse {
+            assert_eq!(data.name, "Test");
+            assert_eq!(data.age, 20);
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(App::new().route("/test", web::post().to(handler))).await;
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .set_json(serde_json::json!({ "name": "Test", "age": 20 }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[actix_web::test]
+    async fn test_body_rejects_wrong_content_type() {
+        async fn handler(_data: Body<TestData, JsonFormat>) -> HttpResponse {
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(App::new().route("/test", web::post().to(handler))).await;
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .insert_header(actix_web::http::header::ContentType::plaintext())
+            .set_payload(r#"{"name":"Test","age":20}"#)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(
+            resp.status(),
+            actix_web::http::StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_body_config_limit() {
+        async fn handler(_data: Body<TestData, JsonFormat>) -> HttpResponse {
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(BodyConfig::default().limit(10))
+                .route("/test", web::post().to(handler)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .set_json(serde_json::json!({ "name": "Test", "age": 20 }))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), actix_web::http::StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[actix_web::test]
+    async fn test_body_with_msgpack_format() {
+        async fn handler(data: Body<TestData, MsgPackFormat>) -> HttpResponse {
+            assert_eq!(data.name, "Test");
+            assert_eq!(data.age, 20);
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(App::new().route("/test", web::post().to(handler))).await;
+
+        let payload = rmp_serde::to_vec(&TestData {
+            name: "Test".to_string(),
+            age: 20,
+        })
+        .unwrap();
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .insert_header((actix_web::http::header::CONTENT_TYPE, "application/msgpack"))
+            .set_payload(payload)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
+    }
+
+    #[cfg(feature = "cbor")]
+    #[actix_web::test]
+    async fn test_body_with_cbor_format() {
+        async fn handler(data: Body<TestData, CborFormat>) -> HttpResponse {
+            assert_eq!(data.name, "Test");
+            assert_eq!(data.age, 20);
+            HttpResponse::Ok().finish()
+        }
+
+        let app = test::init_service(App::new().route("/test", web::post().to(handler))).await;
+
+        let mut payload = Vec::new();
+        ciborium::into_writer(
+            &TestData {
+                name: "Test".to_string(),
+                age: 20,
+            },
+            &mut payload,
+        )
+        .unwrap();
+
+        let req = test::TestRequest::post()
+            .uri("/test")
+            .insert_header((actix_web::http::header::CONTENT_TYPE, "application/cbor"))
+            .set_payload(payload)
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        assert!(resp.status().is_success());
     }
 }